@@ -109,6 +109,7 @@ use std::io;
 /// | [`[T; N]`](array) | `[T, T, ..., T]` (an `N`-tuple) |
 // FIXME: https://github.com/rust-lang/rust/issues/86375
 /// | [`Option<T>`] | <code>T \| null</code> |
+/// | [`Result<T, E>`] | <code>{ "Ok": T } \| { "Err": E }</code> |
 /// | [`Vec<T>`] | `T[]` |
 /// | [`[T]`](slice) | `T[]` |
 /// | [`HashSet<T>`](std::collections::HashSet) | `T[]` |
@@ -124,6 +125,22 @@ use std::io;
 /// PascalCase (e.g. `Usize`, `I32`, `F64`, `NonZeroI8`, etc.). Since they are
 /// simple aliases they do not enforce anything in TypeScript about the Rust
 /// types' numeric bounds, but serve to document their intended range.
+///
+/// Note: `Vec<u8>`, `&[u8]`, and `[u8; N]` are plain `number[]`/`N`-tuples of
+/// `number` *unconditionally* — [`bytes_as_uint8array`] does **not** affect
+/// them. They go through the blanket [`Vec<T>`], [`[T]`](slice), and
+/// [`[T; N]`](array) impls above, which are generic over every `T`; since
+/// [`TypeDef::INFO`] is a const rather than a method, there is no
+/// specialization path that lets a `u8` element conditionally override those
+/// blanket impls without a conflicting-impls compile error. To get
+/// `Uint8Array` output, switch the field's Rust type to
+/// [`serde_bytes::ByteBuf`] (owned) or [`serde_bytes::Bytes`] (borrowed), the
+/// same way you would opt into efficient byte serialization with [`serde`]
+/// itself; then [`bytes_as_uint8array`] applies. `#[serde(with =
+/// "serde_bytes")]` alone does not change the emitted type of a `Vec<u8>`
+/// field; pair it with `#[type_def(type_of = "serde_bytes::ByteBuf")]`.
+///
+/// [`bytes_as_uint8array`]: DefinitionFileOptions::bytes_as_uint8array
 pub trait TypeDef: 'static {
     /// A constant value describing the structure of this type.
     ///
@@ -135,6 +152,35 @@ pub(crate) struct EmitCtx<'ctx> {
     w: &'ctx mut dyn io::Write,
     options: DefinitionFileOptions<'ctx>,
     stats: Stats,
+    indent: usize,
+    /// Fully-qualified names (`path.to.Foo`) of definitions already written
+    /// to `w`, so that emitting multiple root types
+    /// ([`write_definition_file_many`]) does not duplicate a dependency they
+    /// share.
+    emitted: std::collections::HashSet<String>,
+}
+
+impl EmitCtx<'_> {
+    /// Writes a newline followed by the current indentation, if
+    /// [`pretty`](DefinitionFileOptions::pretty) is enabled. Otherwise, does
+    /// nothing.
+    fn newline(&mut self) -> io::Result<()> {
+        if self.options.pretty {
+            writeln!(self.w)?;
+            write!(self.w, "{}", "  ".repeat(self.indent))?;
+        }
+        Ok(())
+    }
+
+    fn indented<R>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> io::Result<R>,
+    ) -> io::Result<R> {
+        self.indent += 1;
+        let result = f(self);
+        self.indent -= 1;
+        result
+    }
 }
 
 pub(crate) trait Emit {
@@ -145,11 +191,14 @@ pub(crate) trait Emit {
 ///
 /// The default options are:
 /// ```
-/// # use typescript_type_def::DefinitionFileOptions;
+/// # use typescript_type_def::{DefinitionFileOptions, OptionalFieldStyle};
 /// # let default =
 /// DefinitionFileOptions {
 ///     header: Some("// AUTO-GENERATED by typescript-type-def\n"),
 ///     root_namespace: Some("types"),
+///     pretty: false,
+///     bytes_as_uint8array: false,
+///     optional_fields: OptionalFieldStyle::Null,
 /// }
 /// # ;
 /// # assert_eq!(default, Default::default());
@@ -196,13 +245,105 @@ pub struct DefinitionFileOptions<'a> {
     /// through the `#[type_def(namespace = "x.y.z")]` attribute, you want to
     /// keep the `root_namespace` as well.
     pub root_namespace: Option<&'a str>,
+    /// Whether to indent and add newlines to the generated code so it is
+    /// human-readable without running it through an external formatter.
+    ///
+    /// By default, this is `false` and the generated code is as compact as
+    /// possible (see the note on [`write_definition_file`]). When set to
+    /// `true`, object fields, union/intersection members, tuple elements, and
+    /// namespace blocks are each placed on their own indented line.
+    ///
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use typescript_type_def::{
+    ///     write_definition_file, DefinitionFileOptions, TypeDef,
+    /// };
+    ///
+    /// #[derive(Debug, Serialize, Deserialize, TypeDef)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// let options = DefinitionFileOptions {
+    ///     pretty: true,
+    ///     ..Default::default()
+    /// };
+    /// let ts_module = {
+    ///     let mut buf = Vec::new();
+    ///     write_definition_file::<_, User>(&mut buf, options).unwrap();
+    ///     String::from_utf8(buf).unwrap()
+    /// };
+    /// assert!(ts_module.contains("{\n    \"name\":string;\n  }"));
+    /// ```
+    pub pretty: bool,
+    /// Whether to emit byte sequences opted in via `serde_bytes::ByteBuf`/
+    /// `serde_bytes::Bytes` as `Uint8Array` instead of `number[]`.
+    ///
+    /// Plain `Vec<u8>`/`&[u8]`/`[u8; N]` are unaffected by this option; they
+    /// always emit `number[]`/an `N`-tuple of `number`, the same as any
+    /// other element type, since they go through the blanket `Vec<T>`/
+    /// `[T]`/`[T; N]` impls rather than a `u8`-specific one.
+    ///
+    /// This matches how a `serde` transport that is aware of bytes (such as
+    /// [`rmp-serde`](https://docs.rs/rmp-serde) or `serde_bytes`) actually
+    /// encodes binary data, and lets frontend code receive a typed array
+    /// instead of a plain array of numbers.
+    pub bytes_as_uint8array: bool,
+    /// How [`Option<T>`] struct fields are rendered.
+    ///
+    /// See [`OptionalFieldStyle`] for the available styles.
+    pub optional_fields: OptionalFieldStyle,
 }
 
-/// Statistics about the type definitions produced by [`write_definition_file`].
+/// The TypeScript rendering of an [`Option<T>`] struct field, controlled by
+/// [`DefinitionFileOptions::optional_fields`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionalFieldStyle {
+    /// Render as `"field": (T | null)`. This is the default, and matches the
+    /// JSON representation `serde_json` produces for `Option<T>` fields
+    /// that are not skipped when `None`.
+    Null,
+    /// Render as `"field"?: T`, dropping the `| null` from the type. This
+    /// suits `wasm-bindgen`-oriented consumers where the field may simply be
+    /// absent rather than explicitly `null`.
+    ///
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use typescript_type_def::{
+    ///     write_definition_file, DefinitionFileOptions, OptionalFieldStyle,
+    ///     TypeDef,
+    /// };
+    ///
+    /// #[derive(Debug, Serialize, Deserialize, TypeDef)]
+    /// struct User {
+    ///     name: String,
+    ///     nickname: Option<String>,
+    /// }
+    ///
+    /// let options = DefinitionFileOptions {
+    ///     optional_fields: OptionalFieldStyle::Undefined,
+    ///     ..Default::default()
+    /// };
+    /// let ts_module = {
+    ///     let mut buf = Vec::new();
+    ///     write_definition_file::<_, User>(&mut buf, options).unwrap();
+    ///     String::from_utf8(buf).unwrap()
+    /// };
+    /// assert!(ts_module.contains(r#""nickname"?:string;"#));
+    /// ```
+    Undefined,
+    /// Render as `"field"?: (T | null)`, combining both of the above.
+    NullableOptional,
+}
 #[derive(Debug, Clone)]
 pub struct Stats {
     /// The number of unique type definitions produced.
     pub type_definitions: usize,
+    /// The number of definitions that were skipped because they were
+    /// already produced as a transitive dependency of an earlier root type,
+    /// when using [`write_definition_file_many`]. Always `0` for
+    /// [`write_definition_file`].
+    pub shared_definitions: usize,
 }
 
 impl<'ctx> EmitCtx<'ctx> {
@@ -212,8 +353,15 @@ impl<'ctx> EmitCtx<'ctx> {
     ) -> Self {
         let stats = Stats {
             type_definitions: 0,
+            shared_definitions: 0,
         };
-        Self { w, options, stats }
+        Self {
+            w,
+            options,
+            stats,
+            indent: 0,
+            emitted: std::collections::HashSet::new(),
+        }
     }
 }
 
@@ -257,9 +405,13 @@ where
 impl Emit for TypeExpr {
     fn emit(&self, ctx: &mut EmitCtx<'_>) -> io::Result<()> {
         match self {
-            TypeExpr::Ref(TypeInfo::Native(NativeTypeInfo { r#ref })) => {
-                r#ref.emit(ctx)
-            }
+            TypeExpr::Ref(TypeInfo::Native(NativeTypeInfo {
+                r#ref,
+                bytes_ref,
+            })) => match (ctx.options.bytes_as_uint8array, bytes_ref) {
+                (true, Some(bytes_ref)) => bytes_ref.emit(ctx),
+                _ => r#ref.emit(ctx),
+            },
             TypeExpr::Ref(TypeInfo::Defined(DefinedTypeInfo {
                 def:
                     TypeDefinition {
@@ -326,7 +478,21 @@ impl Emit for TypeTuple {
         let Self { docs, elements } = self;
         docs.emit(ctx)?;
         write!(ctx.w, "[")?;
-        SepList(elements, ",").emit(ctx)?;
+        ctx.indented(|ctx| {
+            let mut first = true;
+            for element in *elements {
+                if !first {
+                    write!(ctx.w, ",")?;
+                }
+                ctx.newline()?;
+                element.emit(ctx)?;
+                first = false;
+            }
+            io::Result::Ok(())
+        })?;
+        if !elements.is_empty() {
+            ctx.newline()?;
+        }
         write!(ctx.w, "]")?;
         Ok(())
     }
@@ -337,21 +503,41 @@ impl Emit for TypeObject {
         let Self { docs, fields } = self;
         docs.emit(ctx)?;
         write!(ctx.w, "{{")?;
-        for ObjectField {
-            docs,
-            name,
-            optional,
-            r#type,
-        } in *fields
-        {
-            docs.emit(ctx)?;
-            name.emit(ctx)?;
-            if *optional {
-                write!(ctx.w, "?")?;
+        ctx.indented(|ctx| {
+            for ObjectField {
+                docs,
+                name,
+                optional,
+                r#type,
+            } in *fields
+            {
+                ctx.newline()?;
+                docs.emit(ctx)?;
+                name.emit(ctx)?;
+                let nullable = r#type.as_nullable();
+                let question_mark = *optional
+                    || (nullable.is_some()
+                        && matches!(
+                            ctx.options.optional_fields,
+                            OptionalFieldStyle::Undefined
+                                | OptionalFieldStyle::NullableOptional
+                        ));
+                if question_mark {
+                    write!(ctx.w, "?")?;
+                }
+                write!(ctx.w, ":")?;
+                match (ctx.options.optional_fields, nullable) {
+                    (OptionalFieldStyle::Undefined, Some(inner)) => {
+                        inner.emit(ctx)?
+                    }
+                    _ => r#type.emit(ctx)?,
+                }
+                write!(ctx.w, ";")?;
             }
-            write!(ctx.w, ":")?;
-            r#type.emit(ctx)?;
-            write!(ctx.w, ";")?;
+            io::Result::Ok(())
+        })?;
+        if !fields.is_empty() {
+            ctx.newline()?;
         }
         write!(ctx.w, "}}")?;
         Ok(())
@@ -377,7 +563,19 @@ impl Emit for TypeUnion {
             write!(ctx.w, "never")?;
         } else {
             write!(ctx.w, "(")?;
-            SepList(members, "|").emit(ctx)?;
+            ctx.indented(|ctx| {
+                let mut first = true;
+                for member in *members {
+                    if !first {
+                        write!(ctx.w, "|")?;
+                    }
+                    ctx.newline()?;
+                    member.emit(ctx)?;
+                    first = false;
+                }
+                io::Result::Ok(())
+            })?;
+            ctx.newline()?;
             write!(ctx.w, ")")?;
         }
         Ok(())
@@ -392,7 +590,19 @@ impl Emit for TypeIntersection {
             write!(ctx.w, "any")?;
         } else {
             write!(ctx.w, "(")?;
-            SepList(members, "&").emit(ctx)?;
+            ctx.indented(|ctx| {
+                let mut first = true;
+                for member in *members {
+                    if !first {
+                        write!(ctx.w, "&")?;
+                    }
+                    ctx.newline()?;
+                    member.emit(ctx)?;
+                    first = false;
+                }
+                io::Result::Ok(())
+            })?;
+            ctx.newline()?;
             write!(ctx.w, ")")?;
         }
         Ok(())
@@ -410,12 +620,17 @@ impl Emit for Ident {
 impl Emit for Docs {
     fn emit(&self, ctx: &mut EmitCtx<'_>) -> io::Result<()> {
         let Self(docs) = self;
+        let indent = if ctx.options.pretty {
+            "  ".repeat(ctx.indent)
+        } else {
+            String::new()
+        };
         writeln!(ctx.w)?;
-        writeln!(ctx.w, "/**")?;
+        writeln!(ctx.w, "{}/**", indent)?;
         for line in docs.lines() {
-            writeln!(ctx.w, " * {}", line)?;
+            writeln!(ctx.w, "{} * {}", indent, line)?;
         }
-        writeln!(ctx.w, " */")?;
+        writeln!(ctx.w, "{} */", indent)?;
         Ok(())
     }
 }
@@ -452,12 +667,24 @@ impl EmitCtx<'_> {
             def,
         } in crate::iter_def_deps::IterDefDeps::new(info)
         {
+            if !self
+                .emitted
+                .insert(crate::type_expr::full_path_name(path, name))
+            {
+                self.stats.shared_definitions += 1;
+                continue;
+            }
             self.stats.type_definitions += 1;
             docs.emit(self)?;
+            if self.options.pretty {
+                write!(self.w, "{}", "  ".repeat(self.indent))?;
+            }
             if !path.is_empty() {
                 write!(self.w, "export namespace ")?;
                 SepList(path, ".").emit(self)?;
                 write!(self.w, "{{")?;
+                self.indent += 1;
+                self.newline()?;
             }
             write!(self.w, "export type ")?;
             name.emit(self)?;
@@ -466,6 +693,8 @@ impl EmitCtx<'_> {
             def.emit(self)?;
             write!(self.w, ";")?;
             if !path.is_empty() {
+                self.indent -= 1;
+                self.newline()?;
                 write!(self.w, "}}")?;
             }
             writeln!(self.w)?;
@@ -479,6 +708,9 @@ impl Default for DefinitionFileOptions<'_> {
         Self {
             header: Some("// AUTO-GENERATED by typescript-type-def\n"),
             root_namespace: Some("types"),
+            pretty: false,
+            bytes_as_uint8array: false,
+            optional_fields: OptionalFieldStyle::Null,
         }
     }
 }
@@ -505,12 +737,70 @@ impl Default for DefinitionFileOptions<'_> {
 /// human-readable. To make the code human-readable, use a TypeScript code
 /// formatter (such as [Prettier](https://prettier.io/)) on the output.
 pub fn write_definition_file<W, T: ?Sized>(
-    mut writer: W,
+    writer: W,
     options: DefinitionFileOptions<'_>,
 ) -> io::Result<Stats>
 where
     W: io::Write,
     T: TypeDef,
+{
+    write_definition_file_many(writer, options, &[&T::INFO])
+}
+
+/// Writes a TypeScript definition file containing type definitions for
+/// multiple root types to the writer `W`, in a single module.
+///
+/// This is the multi-entrypoint counterpart to [`write_definition_file`],
+/// for a server or library exposing many request/response types: rather
+/// than calling [`write_definition_file`] once per type (and getting
+/// duplicate transitive dependencies across files, or having to build a
+/// wrapper tuple type), pass all the root [`TypeInfo`]s at once and they
+/// will be emitted into one module with shared dependencies deduplicated.
+///
+/// The returned [`Stats::shared_definitions`] reports how many definitions
+/// were already emitted as a dependency of an earlier root and thus skipped
+/// for a later one.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typescript_type_def::{write_definition_file_many, DefinitionFileOptions, TypeDef};
+///
+/// #[derive(Debug, Serialize, Deserialize, TypeDef)]
+/// struct Address {
+///     city: String,
+/// }
+///
+/// #[derive(Debug, Serialize, Deserialize, TypeDef)]
+/// struct CreateUserRequest {
+///     name: String,
+///     address: Address,
+/// }
+///
+/// #[derive(Debug, Serialize, Deserialize, TypeDef)]
+/// struct UpdateUserRequest {
+///     name: String,
+///     address: Address,
+/// }
+///
+/// let mut buf = Vec::new();
+/// let stats = write_definition_file_many(
+///     &mut buf,
+///     Default::default(),
+///     &[&CreateUserRequest::INFO, &UpdateUserRequest::INFO],
+/// )
+/// .unwrap();
+/// let ts_module = String::from_utf8(buf).unwrap();
+/// // `Address` is only emitted once even though both roots depend on it.
+/// assert_eq!(ts_module.matches("export type Address=").count(), 1);
+/// assert_eq!(stats.shared_definitions, 1);
+/// ```
+pub fn write_definition_file_many<W>(
+    mut writer: W,
+    options: DefinitionFileOptions<'_>,
+    roots: &[&'static TypeInfo],
+) -> io::Result<Stats>
+where
+    W: io::Write,
 {
     let mut ctx = EmitCtx::new(&mut writer, options);
     if let Some(header) = &ctx.options.header {
@@ -519,9 +809,13 @@ where
     if let Some(root_namespace) = options.root_namespace {
         writeln!(ctx.w, "export default {};", root_namespace)?;
         writeln!(ctx.w, "export namespace {}{{", root_namespace)?;
+        ctx.indent += 1;
+    }
+    for info in roots {
+        ctx.emit_type(info)?;
     }
-    ctx.emit_type(&T::INFO)?;
     if options.root_namespace.is_some() {
+        ctx.indent -= 1;
         writeln!(ctx.w, "}}")?;
     }
     Ok(ctx.stats)
@@ -0,0 +1,219 @@
+use crate::{
+    type_expr::{
+        Ident, NativeTypeInfo, ObjectField, TypeArray, TypeExpr, TypeInfo,
+        TypeName, TypeObject, TypeString, TypeUnion,
+    },
+    TypeDef,
+};
+
+/// The TypeScript type byte sequences are emitted as when
+/// [`bytes_as_uint8array`](crate::DefinitionFileOptions::bytes_as_uint8array)
+/// is enabled.
+#[cfg(feature = "serde_bytes")]
+const UINT8ARRAY: TypeExpr = TypeExpr::Name(TypeName {
+    path: &[],
+    name: Ident("Uint8Array"),
+    generic_args: &[],
+});
+
+/// The [`TypeInfo`] shared by [`serde_bytes::ByteBuf`] and
+/// [`serde_bytes::Bytes`]: `number[]` by default, or `Uint8Array` when
+/// [`bytes_as_uint8array`](crate::DefinitionFileOptions::bytes_as_uint8array)
+/// is enabled.
+///
+/// This can't live on `Vec<u8>`/`&'static [u8]`/`[u8; N]` themselves: those
+/// go through the blanket `Vec<T>`/`[T]`/`[T; N]` impls, and since
+/// `TypeDef::INFO` is a const (not a method), there's no specialization path
+/// that lets a `u8` element conditionally override those blanket impls
+/// without a conflicting-impls error. `serde` has the exact same limitation,
+/// which is why `serde_bytes` exists as an opt-in wrapper rather than serde
+/// special-casing `Vec<u8>` — this mirrors that.
+#[cfg(feature = "serde_bytes")]
+const BYTES_INFO: TypeInfo = TypeInfo::Native(NativeTypeInfo {
+    r#ref: TypeExpr::Array(TypeArray {
+        docs: None,
+        item: &TypeExpr::Ref(&u8::INFO),
+    }),
+    bytes_ref: Some(UINT8ARRAY),
+});
+
+/// [`serde_bytes::ByteBuf`] serializes identically to `Vec<u8>`, so it gets
+/// the same `number[]`/`Uint8Array` representation.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typescript_type_def::{
+///     write_definition_file, DefinitionFileOptions, TypeDef,
+/// };
+///
+/// #[derive(Debug, Serialize, Deserialize, TypeDef)]
+/// struct Blob {
+///     data: serde_bytes::ByteBuf,
+/// }
+///
+/// let emit = |bytes_as_uint8array| {
+///     let options = DefinitionFileOptions {
+///         bytes_as_uint8array,
+///         ..Default::default()
+///     };
+///     let mut buf = Vec::new();
+///     write_definition_file::<_, Blob>(&mut buf, options).unwrap();
+///     String::from_utf8(buf).unwrap()
+/// };
+/// assert!(emit(true).contains(r#""data":Uint8Array;"#));
+/// assert!(!emit(false).contains("Uint8Array"));
+/// ```
+#[cfg(feature = "serde_bytes")]
+impl TypeDef for serde_bytes::ByteBuf {
+    const INFO: TypeInfo = BYTES_INFO;
+}
+
+/// [`serde_bytes::Bytes`] is the borrowed counterpart of
+/// [`serde_bytes::ByteBuf`]; it serializes identically, so it gets the same
+/// `number[]`/`Uint8Array` representation.
+#[cfg(feature = "serde_bytes")]
+impl TypeDef for &'static serde_bytes::Bytes {
+    const INFO: TypeInfo = BYTES_INFO;
+}
+
+/// The field names used to represent a [`Result<T, E>`]-shaped value as a
+/// TypeScript discriminated union, as selected by a [`ResultTag`].
+///
+/// `Result` itself (via the blanket impl below) always uses [`DefaultTag`].
+/// To describe a type using different field names for the same *externally
+/// tagged* shape (`{ "field_name": T }`, i.e. a `#[serde(rename = "...")]`
+/// on each variant of a hand-written enum, not `#[serde(tag = "...",
+/// content = "...")]`, which produces a differently-shaped *adjacently
+/// tagged* `{ "tag": "...", "content": T }` encoding that this does not
+/// model), implement [`ResultTag`] on a zero-sized marker type and use
+/// [`TaggedResult<T, E, Tag>`] in its place.
+/// As with any other [`TypeDef`] impl, this only describes the shape; you
+/// are still responsible for a [`Serialize`](serde::Serialize)/
+/// [`Deserialize`](serde::Deserialize) impl (manual, or a hand-written enum)
+/// that actually produces/consumes those same field names on the wire.
+pub trait ResultTag: 'static {
+    /// The field name used for the success case (`"Ok"` for [`DefaultTag`]).
+    const OK: &'static str;
+    /// The field name used for the failure case (`"Err"` for
+    /// [`DefaultTag`]).
+    const ERR: &'static str;
+}
+
+/// The [`ResultTag`] used by the blanket [`TypeDef for Result<T, E>`] impl:
+/// serde's default externally-tagged `Result` encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultTag;
+
+impl ResultTag for DefaultTag {
+    const OK: &'static str = "Ok";
+    const ERR: &'static str = "Err";
+}
+
+const fn result_type_info<T, E, Tag>() -> TypeInfo
+where
+    T: TypeDef,
+    E: TypeDef,
+    Tag: ResultTag,
+{
+    TypeInfo::Native(NativeTypeInfo {
+        r#ref: TypeExpr::Union(TypeUnion {
+            docs: None,
+            members: &[
+                TypeExpr::Object(TypeObject {
+                    docs: None,
+                    fields: &[ObjectField {
+                        docs: None,
+                        name: TypeString {
+                            docs: None,
+                            value: Tag::OK,
+                        },
+                        optional: false,
+                        r#type: TypeExpr::Ref(&T::INFO),
+                    }],
+                }),
+                TypeExpr::Object(TypeObject {
+                    docs: None,
+                    fields: &[ObjectField {
+                        docs: None,
+                        name: TypeString {
+                            docs: None,
+                            value: Tag::ERR,
+                        },
+                        optional: false,
+                        r#type: TypeExpr::Ref(&E::INFO),
+                    }],
+                }),
+            ],
+        }),
+        bytes_ref: None,
+    })
+}
+
+/// [`Result<T, E>`] is represented as the discriminated union `{ "Ok": T } |
+/// { "Err": E }`, matching the JSON [`serde`] produces for the default
+/// externally-tagged enum representation (i.e. `Result` with no `#[serde(...)]`
+/// attributes).
+///
+/// If your API uses the same externally tagged shape but with different
+/// field names (e.g. `{ "success": T } | { "failure": E }`), use
+/// [`TaggedResult<T, E, Tag>`] with a [`ResultTag`] that names your fields
+/// instead. This does not model `#[serde(tag = "...", content = "...")]`,
+/// which produces an *adjacently tagged* `{ "tag": "...", "content": T }`
+/// shape, not an externally tagged one.
+impl<T, E> TypeDef for Result<T, E>
+where
+    T: TypeDef,
+    E: TypeDef,
+{
+    const INFO: TypeInfo = result_type_info::<T, E, DefaultTag>();
+}
+
+/// A [`Result<T, E>`]-shaped value tagged with field names other than the
+/// default `"Ok"`/`"Err"`, for APIs whose fallible results use the same
+/// externally tagged shape (`{ "field_name": T }`) under different field
+/// names. This does not cover `#[serde(tag = "...", content = "...")]`,
+/// which is an adjacently tagged shape (`{ "tag": "...", "content": T }`)
+/// that this type does not represent.
+///
+/// ```
+/// use typescript_type_def::{
+///     write_definition_file, DefinitionFileOptions, ResultTag, TaggedResult,
+///     TypeDef,
+/// };
+///
+/// struct SuccessFailureTag;
+/// impl ResultTag for SuccessFailureTag {
+///     const OK: &'static str = "success";
+///     const ERR: &'static str = "failure";
+/// }
+///
+/// // A native type such as `TaggedResult` gets no definition of its own, so
+/// // it is emitted through a field of a derived type.
+/// #[derive(TypeDef)]
+/// struct Response {
+///     result: TaggedResult<String, String, SuccessFailureTag>,
+/// }
+///
+/// let ts_module = {
+///     let mut buf = Vec::new();
+///     write_definition_file::<_, Response>(&mut buf, Default::default())
+///         .unwrap();
+///     String::from_utf8(buf).unwrap()
+/// };
+/// assert!(ts_module.contains(r#""success":string"#));
+/// assert!(ts_module.contains(r#""failure":string"#));
+/// ```
+pub struct TaggedResult<T, E, Tag>(std::marker::PhantomData<(T, E, Tag)>)
+where
+    T: TypeDef,
+    E: TypeDef,
+    Tag: ResultTag;
+
+impl<T, E, Tag> TypeDef for TaggedResult<T, E, Tag>
+where
+    T: TypeDef,
+    E: TypeDef,
+    Tag: ResultTag,
+{
+    const INFO: TypeInfo = result_type_info::<T, E, Tag>();
+}
@@ -0,0 +1,181 @@
+//! The types that make up the structure of a [`TypeInfo`], i.e. the
+//! constant-evaluable tree that [`TypeDef::INFO`](crate::TypeDef::INFO)
+//! produces and that [`emit`](crate::emit) walks to print TypeScript (or,
+//! via [`zod`](crate::zod), Zod) source.
+
+/// An identifier, such as a type or field name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ident(pub &'static str);
+
+/// A documentation comment attached to a type or field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Docs(pub &'static str);
+
+/// The fully-qualified, dot-joined name of a definition (`path.to.Foo`),
+/// used by the emit layers to identify a [`TypeDefinition`] irrespective of
+/// which root type it was reached from.
+pub(crate) fn full_path_name(path: &'static [Ident], name: Ident) -> String {
+    let mut full_name = String::new();
+    for path_part in path {
+        full_name.push_str(path_part.0);
+        full_name.push('.');
+    }
+    full_name.push_str(name.0);
+    full_name
+}
+
+/// Either a reference to a [`TypeDef`](crate::TypeDef)-implementing type, or
+/// an inline type expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeExpr {
+    Ref(&'static TypeInfo),
+    Name(TypeName),
+    String(TypeString),
+    Tuple(TypeTuple),
+    Object(TypeObject),
+    Array(TypeArray),
+    Union(TypeUnion),
+    Intersection(TypeIntersection),
+}
+
+/// Information about a [`TypeDef`](crate::TypeDef)-implementing type: either
+/// a built-in ("native") type, or a user-defined one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeInfo {
+    Native(NativeTypeInfo),
+    Defined(DefinedTypeInfo),
+}
+
+/// The [`TypeExpr`] a built-in Rust type (such as [`String`] or [`Vec<T>`])
+/// is represented as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NativeTypeInfo {
+    pub r#ref: TypeExpr,
+    /// An alternate representation to use instead of `r#ref` when this type
+    /// is `serde_bytes::ByteBuf` or `serde_bytes::Bytes` and
+    /// [`bytes_as_uint8array`](crate::DefinitionFileOptions::bytes_as_uint8array)
+    /// is enabled. `None` for every other native type, including `Vec<u8>`,
+    /// `&[u8]` and `[u8; N]`.
+    pub bytes_ref: Option<TypeExpr>,
+}
+
+/// A reference to a user-defined type, along with the generic arguments it
+/// is instantiated with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefinedTypeInfo {
+    pub def: TypeDefinition,
+    pub generic_args: &'static [TypeExpr],
+}
+
+/// The full definition of a user-defined type, as produced by
+/// `#[derive(TypeDef)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeDefinition {
+    pub docs: Option<Docs>,
+    pub path: &'static [Ident],
+    pub name: Ident,
+    pub generic_vars: &'static [Ident],
+    pub def: TypeExpr,
+}
+
+/// A bare named type, such as a primitive (`string`, `number`, ...) or a
+/// generic type variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeName {
+    pub path: &'static [Ident],
+    pub name: Ident,
+    pub generic_args: &'static [TypeExpr],
+}
+
+/// A string literal type, e.g. the tag of a unit enum variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeString {
+    pub docs: Option<Docs>,
+    pub value: &'static str,
+}
+
+/// A tuple type, e.g. `[A, B, C]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeTuple {
+    pub docs: Option<Docs>,
+    pub elements: &'static [TypeExpr],
+}
+
+/// A single field of a [`TypeObject`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectField {
+    pub docs: Option<Docs>,
+    pub name: TypeString,
+    pub optional: bool,
+    pub r#type: TypeExpr,
+}
+
+/// An object (struct-like) type, e.g. `{ "a": A; "b": B; }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeObject {
+    pub docs: Option<Docs>,
+    pub fields: &'static [ObjectField],
+}
+
+/// An array type, e.g. `(T)[]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeArray {
+    pub docs: Option<Docs>,
+    pub item: &'static TypeExpr,
+}
+
+/// A union type, e.g. `A | B | C`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeUnion {
+    pub docs: Option<Docs>,
+    pub members: &'static [TypeExpr],
+}
+
+/// An intersection type, e.g. `A & B & C`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeIntersection {
+    pub docs: Option<Docs>,
+    pub members: &'static [TypeExpr],
+}
+
+/// If `members` is the two-member union `T | null` (or `null | T`) that
+/// `Option<T>` desugars to, returns `T`. Used by the emit layer to recognize
+/// optional fields regardless of how they are rendered.
+pub(crate) fn nullable_union_inner(
+    members: &'static [TypeExpr],
+) -> Option<&'static TypeExpr> {
+    fn is_null(expr: &TypeExpr) -> bool {
+        matches!(
+            expr,
+            TypeExpr::Name(TypeName { path, name, .. })
+                if path.is_empty() && name.0 == "null"
+        )
+    }
+    match members {
+        [a, b] if is_null(a) && !is_null(b) => Some(b),
+        [a, b] if is_null(b) && !is_null(a) => Some(a),
+        _ => None,
+    }
+}
+
+impl TypeExpr {
+    /// If this is the two-member union `T | null` (or `null | T`) that
+    /// `Option<T>` desugars to, returns `T`.
+    ///
+    /// A struct field of type `Option<T>` is represented not as an inline
+    /// [`TypeExpr::Union`] but as a [`TypeExpr::Ref`] to `Option<T>`'s own
+    /// [`NativeTypeInfo`], whose `r#ref` is the union; look through that
+    /// indirection so field-level optionality is still recognized.
+    pub(crate) fn as_nullable(&self) -> Option<&TypeExpr> {
+        match self {
+            TypeExpr::Union(TypeUnion { members, .. }) => {
+                nullable_union_inner(members)
+            }
+            TypeExpr::Ref(TypeInfo::Native(NativeTypeInfo {
+                r#ref,
+                ..
+            })) => r#ref.as_nullable(),
+            _ => None,
+        }
+    }
+}
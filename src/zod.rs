@@ -0,0 +1,405 @@
+use crate::{
+    emit::{DefinitionFileOptions, Stats},
+    iter_def_deps::IterDefDeps,
+    type_expr::{
+        DefinedTypeInfo, Ident, NativeTypeInfo, ObjectField, TypeArray,
+        TypeDefinition, TypeExpr, TypeInfo, TypeIntersection, TypeName,
+        TypeObject, TypeString, TypeTuple, TypeUnion,
+    },
+    TypeDef,
+};
+use std::{collections::HashSet, io};
+
+/// Writes a [Zod](https://zod.dev/) schema file containing runtime
+/// validators for `T` to the writer `W`.
+///
+/// This walks the same [`TypeInfo`]/[`TypeDefinition`] tree as
+/// [`write_definition_file`](crate::write_definition_file), but instead of
+/// `export type` aliases it produces `zod` schemas, so a Rust backend and TS
+/// frontend can share both compile-time types *and* runtime validation of
+/// [`serde_json`] payloads.
+///
+/// For each type definition `Foo`, this emits:
+/// ```typescript
+/// export const FooSchema = /* zod expression */;
+/// export type Foo = z.infer<typeof FooSchema>;
+/// ```
+///
+/// Self-referential (or otherwise not-yet-closed) definitions are handled by
+/// wrapping the reference in `z.lazy(() => FooSchema)`, since a `zod` schema
+/// cannot refer to a `const` that has not finished initializing.
+///
+/// The `options` are shared with [`write_definition_file`], but only `header`
+/// and `root_namespace` are honored; `pretty`, `bytes_as_uint8array` and
+/// `optional_fields` have no effect on the emitted schemas.
+///
+/// `zod` has no first-class notion of a generic schema, so a type definition
+/// with generic parameters (i.e. `#[derive(TypeDef)]` on a generic struct or
+/// enum) cannot be mapped onto a single `const ...Schema`. If `T` or any of
+/// its transitive dependencies is such a generic definition, this returns an
+/// [`io::ErrorKind::Unsupported`] error instead of emitting a broken
+/// reference to an undefined `TSchema`-style constant.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use std::collections::HashMap;
+/// use typescript_type_def::{write_zod_schema_file, DefinitionFileOptions, TypeDef};
+///
+/// #[derive(Debug, Serialize, Deserialize, TypeDef)]
+/// struct Env {
+///     vars: HashMap<String, String>,
+/// }
+///
+/// let zod_module = {
+///     let mut buf = Vec::new();
+///     write_zod_schema_file::<_, Env>(&mut buf, Default::default()).unwrap();
+///     String::from_utf8(buf).unwrap()
+/// };
+/// assert!(zod_module.contains(
+///     "z.object({\"vars\":z.record(z.string(),z.string())})"
+/// ));
+/// ```
+///
+/// Attempting to write a schema for a generic type definition fails:
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use std::io::ErrorKind;
+/// use typescript_type_def::{write_zod_schema_file, DefinitionFileOptions, TypeDef};
+///
+/// #[derive(Debug, Serialize, Deserialize, TypeDef)]
+/// struct Page<T: TypeDef> {
+///     items: Vec<T>,
+/// }
+///
+/// let err = {
+///     let mut buf = Vec::new();
+///     write_zod_schema_file::<_, Page<String>>(&mut buf, Default::default())
+///         .unwrap_err()
+/// };
+/// assert_eq!(err.kind(), ErrorKind::Unsupported);
+/// ```
+pub fn write_zod_schema_file<W, T: ?Sized>(
+    mut writer: W,
+    options: DefinitionFileOptions<'_>,
+) -> io::Result<Stats>
+where
+    W: io::Write,
+    T: TypeDef,
+{
+    let mut ctx = ZodCtx::new(&mut writer, options);
+    if let Some(header) = &ctx.options.header {
+        writeln!(ctx.w, "{}", header)?;
+    }
+    writeln!(ctx.w, "import {{ z }} from \"zod\";")?;
+    writeln!(ctx.w)?;
+    if let Some(root_namespace) = ctx.options.root_namespace {
+        writeln!(ctx.w, "export namespace {}{{", root_namespace)?;
+    }
+    ctx.emit_type(&T::INFO)?;
+    if ctx.options.root_namespace.is_some() {
+        writeln!(ctx.w, "}}")?;
+    }
+    Ok(ctx.stats)
+}
+
+struct ZodCtx<'ctx> {
+    w: &'ctx mut dyn io::Write,
+    options: DefinitionFileOptions<'ctx>,
+    stats: Stats,
+    /// Fully-qualified names (`path.to.Foo`) of definitions whose schema
+    /// `const` has already been closed, i.e. can be referred to directly
+    /// rather than through `z.lazy(...)`.
+    closed: HashSet<String>,
+}
+
+impl<'ctx> ZodCtx<'ctx> {
+    fn new(
+        w: &'ctx mut dyn io::Write,
+        options: DefinitionFileOptions<'ctx>,
+    ) -> Self {
+        Self {
+            w,
+            options,
+            stats: Stats {
+                type_definitions: 0,
+                shared_definitions: 0,
+            },
+            closed: HashSet::new(),
+        }
+    }
+
+    fn emit_type(&mut self, info: &'static TypeInfo) -> io::Result<()> {
+        for TypeDefinition {
+            docs: _,
+            path,
+            name,
+            generic_vars,
+            def,
+        } in IterDefDeps::new(info)
+        {
+            if !generic_vars.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    format!(
+                        "write_zod_schema_file cannot emit a schema for \
+                         generic type `{}`: zod has no first-class generic \
+                         schemas, so generic type definitions are not \
+                         supported by this emitter",
+                        name.0
+                    ),
+                ));
+            }
+            self.stats.type_definitions += 1;
+            if !path.is_empty() {
+                write!(self.w, "export namespace ")?;
+                emit_path(self, path)?;
+                write!(self.w, "{{")?;
+            }
+            write!(self.w, "export const ")?;
+            name.emit_zod(self)?;
+            write!(self.w, "Schema=")?;
+            def.emit_zod(self)?;
+            write!(self.w, ";")?;
+            write!(self.w, "export type ")?;
+            name.emit_zod(self)?;
+            write!(self.w, "=z.infer<typeof ")?;
+            name.emit_zod(self)?;
+            write!(self.w, "Schema>;")?;
+            if !path.is_empty() {
+                write!(self.w, "}}")?;
+            }
+            writeln!(self.w)?;
+            self.closed
+                .insert(crate::type_expr::full_path_name(path, name));
+        }
+        Ok(())
+    }
+}
+
+fn ident_str(ident: &Ident) -> &'static str {
+    ident.0
+}
+
+fn emit_path(ctx: &mut ZodCtx<'_>, path: &'static [Ident]) -> io::Result<()> {
+    for part in path {
+        part.emit_zod(ctx)?;
+        write!(ctx.w, ".")?;
+    }
+    Ok(())
+}
+
+trait EmitZod {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()>;
+}
+
+fn emit_zod_list<T: EmitZod>(
+    ctx: &mut ZodCtx<'_>,
+    items: &[T],
+    open: &str,
+    close: &str,
+) -> io::Result<()> {
+    write!(ctx.w, "{}", open)?;
+    let mut first = true;
+    for item in items {
+        if !first {
+            write!(ctx.w, ",")?;
+        }
+        item.emit_zod(ctx)?;
+        first = false;
+    }
+    write!(ctx.w, "{}", close)?;
+    Ok(())
+}
+
+impl EmitZod for TypeExpr {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        match self {
+            TypeExpr::Ref(TypeInfo::Native(NativeTypeInfo {
+                r#ref,
+                bytes_ref: _,
+            })) => r#ref.emit_zod(ctx),
+            TypeExpr::Ref(TypeInfo::Defined(DefinedTypeInfo {
+                def: TypeDefinition { path, name, .. },
+                generic_args: _,
+            })) => {
+                let target = crate::type_expr::full_path_name(path, *name);
+                let lazy = !ctx.closed.contains(&target);
+                if lazy {
+                    write!(ctx.w, "z.lazy(()=>")?;
+                }
+                if let Some(root_namespace) = ctx.options.root_namespace {
+                    write!(ctx.w, "{}.", root_namespace)?;
+                }
+                emit_path(ctx, path)?;
+                name.emit_zod(ctx)?;
+                write!(ctx.w, "Schema")?;
+                if lazy {
+                    write!(ctx.w, ")")?;
+                }
+                Ok(())
+            }
+            TypeExpr::Name(type_name) => type_name.emit_zod(ctx),
+            TypeExpr::String(type_string) => type_string.emit_zod(ctx),
+            TypeExpr::Tuple(type_tuple) => type_tuple.emit_zod(ctx),
+            TypeExpr::Object(type_object) => type_object.emit_zod(ctx),
+            TypeExpr::Array(type_array) => type_array.emit_zod(ctx),
+            TypeExpr::Union(type_union) => type_union.emit_zod(ctx),
+            TypeExpr::Intersection(type_intersection) => {
+                type_intersection.emit_zod(ctx)
+            }
+        }
+    }
+}
+
+impl EmitZod for TypeName {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        let Self {
+            path,
+            name,
+            generic_args,
+        } = self;
+        if path.is_empty() {
+            match ident_str(name) {
+                "boolean" => write!(ctx.w, "z.boolean()"),
+                "string" => write!(ctx.w, "z.string()"),
+                "number" => write!(ctx.w, "z.number()"),
+                "null" => write!(ctx.w, "z.null()"),
+                "undefined" => write!(ctx.w, "z.undefined()"),
+                "Record" if generic_args.len() == 2 => {
+                    write!(ctx.w, "z.record(")?;
+                    generic_args[0].emit_zod(ctx)?;
+                    write!(ctx.w, ",")?;
+                    generic_args[1].emit_zod(ctx)?;
+                    write!(ctx.w, ")")
+                }
+                // A named TS alias (numeric newtype, generic var, ...): refer
+                // to it by the same name, assuming a sibling `${name}Schema`.
+                _ => write!(ctx.w, "{}Schema", ident_str(name)),
+            }
+        } else {
+            emit_path(ctx, path)?;
+            write!(ctx.w, "{}Schema", ident_str(name))
+        }
+    }
+}
+
+impl EmitZod for TypeString {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        let Self { docs: _, value } = self;
+        write!(ctx.w, "z.literal({:?})", value)
+    }
+}
+
+impl EmitZod for TypeTuple {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        let Self { docs: _, elements } = self;
+        write!(ctx.w, "z.tuple(")?;
+        emit_zod_list(ctx, elements, "[", "]")?;
+        write!(ctx.w, ")")
+    }
+}
+
+impl EmitZod for ObjectField {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        let Self {
+            docs: _,
+            name,
+            optional,
+            r#type,
+        } = self;
+        // `name` is the object key, not a literal-type position, so it must
+        // not go through `TypeString::emit_zod` (which wraps it in
+        // `z.literal(...)`, producing an invalid `z.object({z.literal(...)
+        // :...})`); write the bare quoted key instead, the same as the `.ts`
+        // emitter does for object keys.
+        write!(ctx.w, "{:?}", name.value)?;
+        write!(ctx.w, ":")?;
+        r#type.emit_zod(ctx)?;
+        if *optional {
+            write!(ctx.w, ".optional()")?;
+        }
+        Ok(())
+    }
+}
+
+impl EmitZod for TypeObject {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        let Self { docs: _, fields } = self;
+        write!(ctx.w, "z.object(")?;
+        write!(ctx.w, "{{")?;
+        let mut first = true;
+        for field in *fields {
+            if !first {
+                write!(ctx.w, ",")?;
+            }
+            field.emit_zod(ctx)?;
+            first = false;
+        }
+        write!(ctx.w, "}}")?;
+        write!(ctx.w, ")")
+    }
+}
+
+impl EmitZod for TypeArray {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        let Self { docs: _, item } = self;
+        write!(ctx.w, "z.array(")?;
+        item.emit_zod(ctx)?;
+        write!(ctx.w, ")")
+    }
+}
+
+impl EmitZod for TypeUnion {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        let Self { docs: _, members } = self;
+        if let Some(inner) = crate::type_expr::nullable_union_inner(members) {
+            inner.emit_zod(ctx)?;
+            write!(ctx.w, ".nullable()")
+        } else if members.is_empty() {
+            write!(ctx.w, "z.never()")
+        } else if let [only] = *members {
+            only.emit_zod(ctx)
+        } else {
+            write!(ctx.w, "z.union(")?;
+            emit_zod_list(ctx, members, "[", "]")?;
+            write!(ctx.w, ")")
+        }
+    }
+}
+
+impl EmitZod for TypeIntersection {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        let Self { docs: _, members } = self;
+        if members.is_empty() {
+            write!(ctx.w, "z.any()")
+        } else {
+            let mut first = true;
+            for member in *members {
+                if !first {
+                    write!(ctx.w, ".and(")?;
+                }
+                member.emit_zod(ctx)?;
+                if !first {
+                    write!(ctx.w, ")")?;
+                }
+                first = false;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl EmitZod for Ident {
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        write!(ctx.w, "{}", ident_str(self))
+    }
+}
+
+impl<T> EmitZod for &T
+where
+    T: EmitZod,
+{
+    fn emit_zod(&self, ctx: &mut ZodCtx<'_>) -> io::Result<()> {
+        T::emit_zod(self, ctx)
+    }
+}